@@ -0,0 +1,7 @@
+mod math;
+mod render;
+mod shapes;
+
+pub use math::*;
+pub use render::*;
+pub use shapes::*;
@@ -0,0 +1,60 @@
+use skia_safe as skia;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Point {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Point> for skia::Point {
+    fn from(p: Point) -> Self {
+        skia::Point::new(p.x, p.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rect {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+impl Rect {
+    pub fn new_empty() -> Self {
+        Self::default()
+    }
+
+    pub fn set_ltrb(&mut self, left: f32, top: f32, right: f32, bottom: f32) {
+        self.left = left;
+        self.top = top;
+        self.right = right;
+        self.bottom = bottom;
+    }
+
+    pub fn to_owned(&self) -> Self {
+        *self
+    }
+
+    pub fn width(&self) -> f32 {
+        self.right - self.left
+    }
+
+    pub fn height(&self) -> f32 {
+        self.bottom - self.top
+    }
+
+    pub fn center(&self) -> Point {
+        Point::new((self.left + self.right) / 2., (self.top + self.bottom) / 2.)
+    }
+
+    pub fn to_skia_rect(self) -> skia::Rect {
+        skia::Rect::new(self.left, self.top, self.right, self.bottom)
+    }
+}
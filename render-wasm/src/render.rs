@@ -0,0 +1,47 @@
+use skia_safe as skia;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlendMode {
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+}
+
+impl Default for BlendMode {
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
+impl From<u8> for BlendMode {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Multiply,
+            2 => Self::Screen,
+            3 => Self::Overlay,
+            4 => Self::Darken,
+            5 => Self::Lighten,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl BlendMode {
+    pub fn to_skia_blend_mode(&self) -> skia::BlendMode {
+        match self {
+            Self::Normal => skia::BlendMode::SrcOver,
+            Self::Multiply => skia::BlendMode::Multiply,
+            Self::Screen => skia::BlendMode::Screen,
+            Self::Overlay => skia::BlendMode::Overlay,
+            Self::Darken => skia::BlendMode::Darken,
+            Self::Lighten => skia::BlendMode::Lighten,
+        }
+    }
+}
+
+pub trait Renderable {
+    fn render(&self, canvas: &skia::Canvas);
+}
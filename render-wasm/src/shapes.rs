@@ -6,18 +6,22 @@ use crate::render::{BlendMode, Renderable};
 
 mod bools;
 mod fills;
+mod fixtures;
 mod images;
 mod matrix;
 mod paths;
 mod renderable;
 mod strokes;
+mod svg;
 
 pub use bools::*;
 pub use fills::*;
+pub use fixtures::*;
 pub use images::*;
 use matrix::*;
 pub use paths::*;
 pub use strokes::*;
+pub use svg::*;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Kind {
@@ -127,14 +131,35 @@ impl Shape {
 
     pub fn add_fill_gradient_stops(&mut self, buffer: Vec<RawStopData>) -> Result<(), String> {
         let fill = self.fills.last_mut().ok_or("Shape has no fills")?;
-        let gradient = match fill {
-            Fill::LinearGradient(g) => Ok(g),
-            Fill::RadialGradient(g) => Ok(g),
-            _ => Err("Active fill is not a gradient"),
-        }?;
-
-        for stop in buffer.into_iter() {
-            gradient.add_stop(stop.color(), stop.offset());
+        match fill {
+            Fill::LinearGradient(g) => {
+                for stop in buffer.into_iter() {
+                    g.add_stop(stop.color(), stop.offset());
+                }
+            }
+            Fill::RadialGradient(g) => {
+                for stop in buffer.into_iter() {
+                    g.add_stop(stop.color(), stop.offset());
+                }
+            }
+            Fill::SweepGradient(g) => {
+                for stop in buffer.into_iter() {
+                    g.add_stop(stop.color(), stop.offset());
+                }
+            }
+            _ => return Err("Active fill is not a gradient".to_string()),
+        }
+
+        Ok(())
+    }
+
+    pub fn set_fill_gradient_spread(&mut self, spread: Spread) -> Result<(), String> {
+        let fill = self.fills.last_mut().ok_or("Shape has no fills")?;
+        match fill {
+            Fill::LinearGradient(g) => g.set_spread(spread),
+            Fill::RadialGradient(g) => g.set_spread(spread),
+            Fill::SweepGradient(g) => g.set_spread(spread),
+            _ => return Err("Active fill is not a gradient".to_string()),
         }
 
         Ok(())
@@ -154,17 +179,61 @@ impl Shape {
         Ok(())
     }
 
+    pub fn set_stroke_dash(&mut self, intervals: Vec<f32>, offset: f32) -> Result<(), String> {
+        let stroke = self.strokes.last_mut().ok_or("Shape has no strokes")?;
+        stroke.set_dash(intervals, offset);
+        Ok(())
+    }
+
+    pub fn set_stroke_cap(&mut self, cap: LineCap) -> Result<(), String> {
+        let stroke = self.strokes.last_mut().ok_or("Shape has no strokes")?;
+        stroke.set_cap(cap);
+        Ok(())
+    }
+
+    pub fn set_stroke_join(&mut self, join: LineJoin) -> Result<(), String> {
+        let stroke = self.strokes.last_mut().ok_or("Shape has no strokes")?;
+        stroke.set_join(join);
+        Ok(())
+    }
+
+    pub fn set_stroke_miter_limit(&mut self, miter_limit: f32) -> Result<(), String> {
+        let stroke = self.strokes.last_mut().ok_or("Shape has no strokes")?;
+        stroke.set_miter_limit(miter_limit);
+        Ok(())
+    }
+
     pub fn add_stroke_gradient_stops(&mut self, buffer: Vec<RawStopData>) -> Result<(), String> {
         let stroke = self.strokes.last_mut().ok_or("Shape has no strokes")?;
-        let fill = &mut stroke.fill;
-        let gradient = match fill {
-            Fill::LinearGradient(g) => Ok(g),
-            Fill::RadialGradient(g) => Ok(g),
-            _ => Err("Active stroke is not a gradient"),
-        }?;
-
-        for stop in buffer.into_iter() {
-            gradient.add_stop(stop.color(), stop.offset());
+        match &mut stroke.fill {
+            Fill::LinearGradient(g) => {
+                for stop in buffer.into_iter() {
+                    g.add_stop(stop.color(), stop.offset());
+                }
+            }
+            Fill::RadialGradient(g) => {
+                for stop in buffer.into_iter() {
+                    g.add_stop(stop.color(), stop.offset());
+                }
+            }
+            Fill::SweepGradient(g) => {
+                for stop in buffer.into_iter() {
+                    g.add_stop(stop.color(), stop.offset());
+                }
+            }
+            _ => return Err("Active stroke is not a gradient".to_string()),
+        }
+
+        Ok(())
+    }
+
+    pub fn set_stroke_gradient_spread(&mut self, spread: Spread) -> Result<(), String> {
+        let stroke = self.strokes.last_mut().ok_or("Shape has no strokes")?;
+        match &mut stroke.fill {
+            Fill::LinearGradient(g) => g.set_spread(spread),
+            Fill::RadialGradient(g) => g.set_spread(spread),
+            Fill::SweepGradient(g) => g.set_spread(spread),
+            _ => return Err("Active stroke is not a gradient".to_string()),
         }
 
         Ok(())
@@ -1,3 +1,10 @@
+use std::collections::HashMap;
+
+use skia_safe as skia;
+use uuid::Uuid;
+
+use super::{Kind, Path, Shape};
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum BoolType {
     Union,
@@ -23,3 +30,200 @@ impl Default for BoolType {
         Self::Union
     }
 }
+
+impl BoolType {
+    fn to_skia_path_op(self) -> skia::PathOp {
+        match self {
+            Self::Union => skia::PathOp::Union,
+            Self::Intersection => skia::PathOp::Intersect,
+            Self::Difference => skia::PathOp::Difference,
+            Self::Exclusion => skia::PathOp::XOR,
+        }
+    }
+}
+
+/// A shape's own geometry as a Skia path, with its local `transform`
+/// already baked in. Used as the leaf input to boolean folding.
+fn shape_local_path(shape: &Shape) -> skia::Path {
+    let mut path = match &shape.kind {
+        Kind::Rect(rect) => skia::Path::rect(rect.to_skia_rect(), None),
+        Kind::Circle(rect) => skia::Path::oval(rect.to_skia_rect(), None),
+        Kind::Path(p) => p.to_skia_path(),
+        Kind::Bool(_, p) => p.to_skia_path(),
+    };
+    path.transform(&shape.transform.to_skia_matrix());
+    path
+}
+
+/// Resolves the combined path for `id`, recursing into nested bool groups
+/// bottom-up and caching the result back onto `Kind::Bool`. Non-bool
+/// shapes are returned as their own local path unchanged.
+pub fn resolve_bool_path(id: Uuid, shapes: &mut HashMap<Uuid, Shape>) -> Path {
+    let Some(shape) = shapes.get(&id) else {
+        return Path::default();
+    };
+
+    let (bool_type, children) = match &shape.kind {
+        Kind::Bool(bool_type, _) => (*bool_type, shape.children.clone()),
+        _ => return Path::new(shape_local_path(shape)),
+    };
+
+    let mut combined: Option<skia::Path> = None;
+    for child_id in children {
+        let Some((is_bool, child_transform)) = shapes
+            .get(&child_id)
+            .map(|c| (matches!(c.kind, Kind::Bool(..)), c.transform))
+        else {
+            continue;
+        };
+
+        // A non-bool child's transform is already baked in by
+        // `shape_local_path`. A bool child's own resolved path is in its
+        // *local* space (only its children's transforms are folded in), so
+        // its own transform must still be applied before folding it into
+        // the parent, same as any other child.
+        let child_path = if is_bool {
+            let mut path = resolve_bool_path(child_id, shapes).to_skia_path();
+            path.transform(&child_transform.to_skia_matrix());
+            path
+        } else {
+            shape_local_path(shapes.get(&child_id).unwrap())
+        };
+
+        combined = Some(match combined {
+            None => child_path,
+            Some(acc) => acc
+                .op(&child_path, bool_type.to_skia_path_op())
+                .unwrap_or(acc),
+        });
+    }
+
+    let result = Path::new(combined.unwrap_or_default());
+
+    if let Some(shape) = shapes.get_mut(&id) {
+        // Only the combined geometry is cached — `selrect` (and therefore
+        // `Shape::bounds()`) is left untouched, so the parent's declared
+        // bounds keep governing layout regardless of what the op produced.
+        shape.kind = Kind::Bool(bool_type, result.clone());
+    }
+
+    result
+}
+
+/// Resolves every `Kind::Bool` shape in `shapes`, including ones nested
+/// inside other bool groups. Each bool's combined path is cached back onto
+/// its `Kind::Bool`, so this is the entry point callers (scene loading,
+/// rendering) should use instead of calling `resolve_bool_path` directly
+/// on a single id.
+pub fn resolve_all_bools(shapes: &mut HashMap<Uuid, Shape>) {
+    let bool_ids: Vec<Uuid> = shapes
+        .iter()
+        .filter(|(_, shape)| matches!(shape.kind, Kind::Bool(..)))
+        .map(|(id, _)| *id)
+        .collect();
+
+    for id in bool_ids {
+        resolve_bool_path(id, shapes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect_shape(l: f32, t: f32, r: f32, b: f32) -> Shape {
+        let mut shape = Shape::new(Uuid::new_v4());
+        shape.set_selrect(l, t, r, b);
+        shape
+    }
+
+    #[test]
+    fn union_combines_overlapping_rects_and_keeps_parent_selrect() {
+        let mut shapes = HashMap::new();
+
+        let a = rect_shape(0., 0., 10., 10.);
+        let b = rect_shape(5., 5., 15., 15.);
+        let a_id = a.id;
+        let b_id = b.id;
+        shapes.insert(a_id, a);
+        shapes.insert(b_id, b);
+
+        let mut root = Shape::new(Uuid::new_v4());
+        root.set_selrect(0., 0., 15., 15.);
+        root.set_bool_type(BoolType::Union);
+        root.add_child(a_id);
+        root.add_child(b_id);
+        let root_id = root.id;
+        shapes.insert(root_id, root);
+
+        let result = resolve_bool_path(root_id, &mut shapes).to_skia_path();
+
+        assert!(result.contains(skia::Point::new(2., 2.)));
+        assert!(result.contains(skia::Point::new(12., 12.)));
+        assert!(!result.contains(skia::Point::new(20., 20.)));
+
+        let root_after = shapes.get(&root_id).unwrap();
+        assert_eq!(root_after.bounds().to_skia_rect().left(), 0.);
+        assert_eq!(root_after.bounds().to_skia_rect().right(), 15.);
+    }
+
+    #[test]
+    fn nested_bool_child_keeps_its_own_transform() {
+        let mut shapes = HashMap::new();
+
+        let inner_child = rect_shape(0., 0., 10., 10.);
+        let inner_child_id = inner_child.id;
+        shapes.insert(inner_child_id, inner_child);
+
+        let mut inner_bool = Shape::new(Uuid::new_v4());
+        inner_bool.set_selrect(0., 0., 10., 10.);
+        inner_bool.set_bool_type(BoolType::Union);
+        inner_bool.add_child(inner_child_id);
+        // Shift the inner bool group 20 units to the right.
+        inner_bool.set_transform(1., 0., 0., 1., 20., 0.);
+        let inner_bool_id = inner_bool.id;
+        shapes.insert(inner_bool_id, inner_bool);
+
+        let mut outer = Shape::new(Uuid::new_v4());
+        outer.set_selrect(0., 0., 30., 10.);
+        outer.set_bool_type(BoolType::Union);
+        outer.add_child(inner_bool_id);
+        let outer_id = outer.id;
+        shapes.insert(outer_id, outer);
+
+        let result = resolve_bool_path(outer_id, &mut shapes).to_skia_path();
+
+        assert!(result.contains(skia::Point::new(25., 5.)));
+        assert!(!result.contains(skia::Point::new(5., 5.)));
+    }
+
+    #[test]
+    fn resolve_all_bools_resolves_nested_groups_from_the_top() {
+        let mut shapes = HashMap::new();
+
+        let leaf = rect_shape(0., 0., 10., 10.);
+        let leaf_id = leaf.id;
+        shapes.insert(leaf_id, leaf);
+
+        let mut inner = Shape::new(Uuid::new_v4());
+        inner.set_selrect(0., 0., 10., 10.);
+        inner.set_bool_type(BoolType::Union);
+        inner.add_child(leaf_id);
+        let inner_id = inner.id;
+        shapes.insert(inner_id, inner);
+
+        let mut outer = Shape::new(Uuid::new_v4());
+        outer.set_selrect(0., 0., 10., 10.);
+        outer.set_bool_type(BoolType::Union);
+        outer.add_child(inner_id);
+        let outer_id = outer.id;
+        shapes.insert(outer_id, outer);
+
+        resolve_all_bools(&mut shapes);
+
+        let Kind::Bool(_, inner_path) = &shapes.get(&inner_id).unwrap().kind else {
+            panic!("expected inner shape to stay a bool");
+        };
+        assert!(inner_path.to_skia_path().contains(skia::Point::new(5., 5.)));
+    }
+}
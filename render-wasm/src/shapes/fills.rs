@@ -0,0 +1,345 @@
+use skia_safe as skia;
+use skia_safe::gradient_shader;
+
+use crate::math::Point;
+
+pub type Color = skia::Color;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fill {
+    Solid(Color),
+    LinearGradient(LinearGradient),
+    RadialGradient(RadialGradient),
+    SweepGradient(SweepGradient),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientStop {
+    color: Color,
+    offset: f32,
+}
+
+/// How a gradient behaves outside its defined stop range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Spread {
+    Pad,
+    Repeat,
+    Reflect,
+}
+
+impl Default for Spread {
+    fn default() -> Self {
+        Self::Pad
+    }
+}
+
+impl From<u8> for Spread {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Repeat,
+            2 => Self::Reflect,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl Spread {
+    pub fn to_skia_tile_mode(self) -> skia::TileMode {
+        match self {
+            Self::Pad => skia::TileMode::Clamp,
+            Self::Repeat => skia::TileMode::Repeat,
+            Self::Reflect => skia::TileMode::Mirror,
+        }
+    }
+
+    fn to_svg_spread_method(self) -> &'static str {
+        match self {
+            Self::Pad => "pad",
+            Self::Repeat => "repeat",
+            Self::Reflect => "reflect",
+        }
+    }
+}
+
+fn color_to_svg(color: Color) -> String {
+    format!(
+        "#{:02x}{:02x}{:02x}{:02x}",
+        color.r(),
+        color.g(),
+        color.b(),
+        color.a()
+    )
+}
+
+impl GradientStop {
+    fn to_svg_stop(self) -> String {
+        format!(
+            "<stop offset=\"{}\" stop-color=\"{}\"/>",
+            self.offset,
+            color_to_svg(self.color)
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LinearGradient {
+    start: Point,
+    end: Point,
+    spread: Spread,
+    stops: Vec<GradientStop>,
+}
+
+impl LinearGradient {
+    pub fn new(start: Point, end: Point) -> Self {
+        Self {
+            start,
+            end,
+            spread: Spread::default(),
+            stops: vec![],
+        }
+    }
+
+    pub fn add_stop(&mut self, color: Color, offset: f32) {
+        self.stops.push(GradientStop { color, offset });
+    }
+
+    pub fn set_spread(&mut self, spread: Spread) {
+        self.spread = spread;
+    }
+
+    pub fn to_svg_def(&self, id: &str) -> String {
+        let stops: String = self.stops.iter().map(|s| s.to_svg_stop()).collect();
+        format!(
+            "<linearGradient id=\"{id}\" x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" spreadMethod=\"{}\" gradientUnits=\"userSpaceOnUse\">{stops}</linearGradient>",
+            self.start.x,
+            self.start.y,
+            self.end.x,
+            self.end.y,
+            self.spread.to_svg_spread_method()
+        )
+    }
+
+    pub fn to_shader(&self) -> Option<skia::Shader> {
+        if self.stops.is_empty() {
+            return None;
+        }
+        let colors: Vec<Color> = self.stops.iter().map(|s| s.color).collect();
+        let positions: Vec<f32> = self.stops.iter().map(|s| s.offset).collect();
+        gradient_shader::linear(
+            (self.start, self.end),
+            gradient_shader::GradientShaderColors::Colors(&colors),
+            Some(positions.as_slice()),
+            self.spread.to_skia_tile_mode(),
+            None,
+            None,
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RadialGradient {
+    center: Point,
+    radius: f32,
+    spread: Spread,
+    stops: Vec<GradientStop>,
+}
+
+impl RadialGradient {
+    pub fn new(center: Point, radius: f32) -> Self {
+        Self {
+            center,
+            radius,
+            spread: Spread::default(),
+            stops: vec![],
+        }
+    }
+
+    pub fn add_stop(&mut self, color: Color, offset: f32) {
+        self.stops.push(GradientStop { color, offset });
+    }
+
+    pub fn set_spread(&mut self, spread: Spread) {
+        self.spread = spread;
+    }
+
+    pub fn to_svg_def(&self, id: &str) -> String {
+        let stops: String = self.stops.iter().map(|s| s.to_svg_stop()).collect();
+        format!(
+            "<radialGradient id=\"{id}\" cx=\"{}\" cy=\"{}\" r=\"{}\" spreadMethod=\"{}\" gradientUnits=\"userSpaceOnUse\">{stops}</radialGradient>",
+            self.center.x,
+            self.center.y,
+            self.radius,
+            self.spread.to_svg_spread_method()
+        )
+    }
+
+    pub fn to_shader(&self) -> Option<skia::Shader> {
+        if self.stops.is_empty() {
+            return None;
+        }
+        let colors: Vec<Color> = self.stops.iter().map(|s| s.color).collect();
+        let positions: Vec<f32> = self.stops.iter().map(|s| s.offset).collect();
+        gradient_shader::radial(
+            self.center,
+            self.radius,
+            gradient_shader::GradientShaderColors::Colors(&colors),
+            Some(positions.as_slice()),
+            self.spread.to_skia_tile_mode(),
+            None,
+            None,
+        )
+    }
+}
+
+/// A conic (sweep) gradient, rotating its stops around `center` between
+/// `start_angle` and `end_angle` (both in degrees).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepGradient {
+    center: Point,
+    start_angle: f32,
+    end_angle: f32,
+    spread: Spread,
+    stops: Vec<GradientStop>,
+}
+
+impl SweepGradient {
+    pub fn new(center: Point, start_angle: f32, end_angle: f32) -> Self {
+        Self {
+            center,
+            start_angle,
+            end_angle,
+            spread: Spread::default(),
+            stops: vec![],
+        }
+    }
+
+    pub fn add_stop(&mut self, color: Color, offset: f32) {
+        self.stops.push(GradientStop { color, offset });
+    }
+
+    pub fn set_spread(&mut self, spread: Spread) {
+        self.spread = spread;
+    }
+
+    pub fn to_shader(&self) -> Option<skia::Shader> {
+        if self.stops.is_empty() {
+            return None;
+        }
+        let colors: Vec<Color> = self.stops.iter().map(|s| s.color).collect();
+        let positions: Vec<f32> = self.stops.iter().map(|s| s.offset).collect();
+        gradient_shader::sweep(
+            self.center,
+            gradient_shader::GradientShaderColors::Colors(&colors),
+            Some(positions.as_slice()),
+            self.spread.to_skia_tile_mode(),
+            (self.start_angle, self.end_angle),
+            None,
+        )
+    }
+}
+
+impl Fill {
+    pub fn to_paint(&self, _bounds: &crate::math::Rect) -> skia::Paint {
+        let mut paint = skia::Paint::default();
+        paint.set_anti_alias(true);
+
+        match self {
+            Self::Solid(color) => {
+                paint.set_color(*color);
+            }
+            Self::LinearGradient(gradient) => {
+                if let Some(shader) = gradient.to_shader() {
+                    paint.set_shader(shader);
+                }
+            }
+            Self::RadialGradient(gradient) => {
+                if let Some(shader) = gradient.to_shader() {
+                    paint.set_shader(shader);
+                }
+            }
+            Self::SweepGradient(gradient) => {
+                if let Some(shader) = gradient.to_shader() {
+                    paint.set_shader(shader);
+                }
+            }
+        }
+
+        paint
+    }
+
+    /// Returns the `fill`/`stroke` attribute value for this fill and, for
+    /// gradients, the `<defs>` markup it needs. SVG has no native conic
+    /// gradient, so a sweep gradient degrades to its first stop's solid
+    /// color rather than producing an inaccurate `<linearGradient>`.
+    pub fn to_svg_attr(&self, gradient_id: &str) -> (String, Option<String>) {
+        match self {
+            Self::Solid(color) => (color_to_svg(*color), None),
+            Self::LinearGradient(gradient) => (
+                format!("url(#{gradient_id})"),
+                Some(gradient.to_svg_def(gradient_id)),
+            ),
+            Self::RadialGradient(gradient) => (
+                format!("url(#{gradient_id})"),
+                Some(gradient.to_svg_def(gradient_id)),
+            ),
+            Self::SweepGradient(gradient) => {
+                let color = gradient
+                    .stops
+                    .first()
+                    .map(|s| color_to_svg(s.color))
+                    .unwrap_or_else(|| "none".to_string());
+                (color, None)
+            }
+        }
+    }
+}
+
+/// A single gradient stop as it arrives from the WASM boundary.
+#[derive(Debug, Clone, Copy)]
+pub struct RawStopData {
+    color: Color,
+    offset: f32,
+}
+
+impl RawStopData {
+    pub fn new(color: Color, offset: f32) -> Self {
+        Self { color, offset }
+    }
+
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_gradient_collects_stops_in_order() {
+        let mut gradient = LinearGradient::new(Point::new(0., 0.), Point::new(1., 1.));
+        gradient.add_stop(Color::BLACK, 0.0);
+        gradient.add_stop(Color::WHITE, 1.0);
+        assert_eq!(gradient.stops.len(), 2);
+    }
+
+    #[test]
+    fn sweep_gradient_collects_stops_in_order() {
+        let mut gradient = SweepGradient::new(Point::new(0., 0.), 0.0, 360.0);
+        gradient.add_stop(Color::BLACK, 0.0);
+        gradient.add_stop(Color::WHITE, 1.0);
+        assert_eq!(gradient.stops.len(), 2);
+    }
+
+    #[test]
+    fn spread_decodes_from_u8_with_pad_default() {
+        assert_eq!(Spread::from(0), Spread::Pad);
+        assert_eq!(Spread::from(1), Spread::Repeat);
+        assert_eq!(Spread::from(2), Spread::Reflect);
+        assert_eq!(Spread::from(42), Spread::Pad);
+    }
+}
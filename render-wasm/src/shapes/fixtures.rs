@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::math::Point;
+
+use super::{
+    resolve_all_bools, BoolType, Fill, Kind, LinearGradient, Path, RadialGradient, Shape, Stroke,
+    SweepGradient,
+};
+
+/// One `fills`/`strokes` entry in a fixture: either a solid `#rrggbbaa`
+/// color or a gradient with its stop list.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum FillSpec {
+    Solid(String),
+    Gradient(GradientSpec),
+}
+
+#[derive(Debug, Deserialize)]
+struct GradientSpec {
+    #[serde(rename = "gradient")]
+    kind: String,
+    stops: Vec<StopSpec>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StopSpec {
+    offset: f32,
+    color: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SceneNode {
+    kind: String,
+    selrect: [f32; 4],
+    #[serde(default)]
+    transform: Option<[f32; 6]>,
+    #[serde(default)]
+    rotation: f32,
+    #[serde(default = "default_opacity")]
+    opacity: f32,
+    #[serde(default = "default_true")]
+    clip: bool,
+    #[serde(default)]
+    hidden: bool,
+    #[serde(default)]
+    fills: Vec<FillSpec>,
+    #[serde(default)]
+    strokes: Vec<FillSpec>,
+    #[serde(default)]
+    children: Vec<SceneNode>,
+}
+
+fn default_opacity() -> f32 {
+    1.
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn parse_color(hex: &str) -> Result<super::Color, String> {
+    let hex = hex.trim_start_matches('#');
+    if hex.len() != 8 {
+        return Err(format!("expected #rrggbbaa color, got \"{hex}\""));
+    }
+    let channel = |i: usize| -> Result<u8, String> {
+        u8::from_str_radix(&hex[i..i + 2], 16).map_err(|e| e.to_string())
+    };
+    Ok(super::Color::from_argb(
+        channel(6)?,
+        channel(0)?,
+        channel(2)?,
+        channel(4)?,
+    ))
+}
+
+fn build_fill(spec: &FillSpec) -> Result<Fill, String> {
+    match spec {
+        FillSpec::Solid(hex) => Ok(Fill::Solid(parse_color(hex)?)),
+        FillSpec::Gradient(gradient) => {
+            let mut stops = Vec::with_capacity(gradient.stops.len());
+            for stop in &gradient.stops {
+                stops.push((parse_color(&stop.color)?, stop.offset));
+            }
+
+            let mut fill = match gradient.kind.as_str() {
+                "linear" => Fill::LinearGradient(LinearGradient::new(
+                    Point::new(0., 0.),
+                    Point::new(1., 0.),
+                )),
+                "radial" => Fill::RadialGradient(RadialGradient::new(Point::new(0.5, 0.5), 0.5)),
+                "sweep" => {
+                    Fill::SweepGradient(SweepGradient::new(Point::new(0.5, 0.5), 0., 360.))
+                }
+                other => return Err(format!("unknown gradient kind \"{other}\"")),
+            };
+
+            match &mut fill {
+                Fill::LinearGradient(g) => stops.into_iter().for_each(|(c, o)| g.add_stop(c, o)),
+                Fill::RadialGradient(g) => stops.into_iter().for_each(|(c, o)| g.add_stop(c, o)),
+                Fill::SweepGradient(g) => stops.into_iter().for_each(|(c, o)| g.add_stop(c, o)),
+                _ => unreachable!(),
+            }
+
+            Ok(fill)
+        }
+    }
+}
+
+fn build_node(node: &SceneNode, shapes: &mut HashMap<Uuid, Shape>) -> Result<Uuid, String> {
+    let id = Uuid::new_v4();
+    let mut shape = Shape::new(id);
+
+    let [left, top, right, bottom] = node.selrect;
+    shape.set_selrect(left, top, right, bottom);
+
+    if let Some([a, b, c, d, e, f]) = node.transform {
+        shape.set_transform(a, b, c, d, e, f);
+    }
+    shape.set_rotation(node.rotation);
+    shape.set_opacity(node.opacity);
+    shape.set_clip(node.clip);
+    shape.set_hidden(node.hidden);
+
+    match node.kind.as_str() {
+        "rect" => {}
+        "circle" => shape.set_kind(Kind::Circle(shape.bounds())),
+        "path" => shape.set_kind(Kind::Path(Path::default())),
+        kind if kind.starts_with("bool") => {
+            let bool_type = match kind.splitn(2, ':').nth(1).unwrap_or("union") {
+                "union" => BoolType::Union,
+                "difference" => BoolType::Difference,
+                "intersection" => BoolType::Intersection,
+                "exclusion" => BoolType::Exclusion,
+                other => return Err(format!("unknown bool type \"{other}\"")),
+            };
+            shape.set_bool_type(bool_type);
+        }
+        other => return Err(format!("unknown shape kind \"{other}\"")),
+    }
+
+    for fill in &node.fills {
+        shape.add_fill(build_fill(fill)?);
+    }
+    for stroke in &node.strokes {
+        shape.add_stroke(Stroke::new(build_fill(stroke)?, 1.));
+    }
+
+    for child in &node.children {
+        let child_id = build_node(child, shapes)?;
+        shape.add_child(child_id);
+    }
+
+    shapes.insert(id, shape);
+    Ok(id)
+}
+
+/// Builds a `Shape` tree from a declarative YAML (or JSON, a YAML subset)
+/// fixture, returning the root id and the full `{id -> Shape}` map. This
+/// lets tests and design imports describe a scene without hand-writing
+/// the raw `RawPathData`/byte-buffer WASM API.
+pub fn load_scene(source: &str) -> Result<(Uuid, HashMap<Uuid, Shape>), String> {
+    let root_node: SceneNode = serde_yaml::from_str(source).map_err(|e| e.to_string())?;
+    let mut shapes = HashMap::new();
+    let root_id = build_node(&root_node, &mut shapes)?;
+    resolve_all_bools(&mut shapes);
+    Ok((root_id, shapes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_a_rect_with_a_solid_fill() {
+        let (root_id, shapes) = load_scene(
+            r#"
+            kind: rect
+            selrect: [0, 0, 10, 10]
+            fills:
+              - "#ff0000ff"
+            "#,
+        )
+        .unwrap();
+
+        let shape = shapes.get(&root_id).unwrap();
+        assert_eq!(shape.fills().count(), 1);
+    }
+
+    #[test]
+    fn loads_nested_children() {
+        let (root_id, shapes) = load_scene(
+            r#"
+            kind: bool:union
+            selrect: [0, 0, 10, 10]
+            children:
+              - kind: rect
+                selrect: [0, 0, 5, 5]
+              - kind: circle
+                selrect: [5, 5, 10, 10]
+            "#,
+        )
+        .unwrap();
+
+        let root = shapes.get(&root_id).unwrap();
+        assert!(matches!(root.kind(), Kind::Bool(BoolType::Union, _)));
+        assert_eq!(shapes.len(), 3);
+    }
+
+    #[test]
+    fn loads_a_path_node_as_kind_path_not_a_rect() {
+        let (root_id, shapes) = load_scene(
+            r#"
+            kind: path
+            selrect: [0, 0, 10, 10]
+            "#,
+        )
+        .unwrap();
+
+        let shape = shapes.get(&root_id).unwrap();
+        assert!(matches!(shape.kind(), Kind::Path(_)));
+    }
+}
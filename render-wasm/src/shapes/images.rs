@@ -0,0 +1,22 @@
+use uuid::Uuid;
+
+/// A bitmap fill referencing an image asset stored outside the shape tree.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImageFill {
+    id: Uuid,
+    opacity: f32,
+}
+
+impl ImageFill {
+    pub fn new(id: Uuid, opacity: f32) -> Self {
+        Self { id, opacity }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+}
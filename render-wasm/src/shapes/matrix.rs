@@ -0,0 +1,38 @@
+use skia_safe as skia;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix {
+    a: f32,
+    b: f32,
+    c: f32,
+    d: f32,
+    e: f32,
+    f: f32,
+}
+
+impl Matrix {
+    pub fn identity() -> Self {
+        Self::new(1., 0., 0., 1., 0., 0.)
+    }
+
+    pub fn new(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32) -> Self {
+        Self { a, b, c, d, e, f }
+    }
+
+    pub fn no_translation(&self) -> Self {
+        Self::new(self.a, self.b, self.c, self.d, 0., 0.)
+    }
+
+    pub fn to_skia_matrix(self) -> skia::Matrix {
+        skia::Matrix::new_all(
+            self.a, self.c, self.e, self.b, self.d, self.f, 0., 0., 1.,
+        )
+    }
+
+    pub fn to_svg_matrix(self) -> String {
+        format!(
+            "matrix({}, {}, {}, {}, {}, {})",
+            self.a, self.b, self.c, self.d, self.e, self.f
+        )
+    }
+}
@@ -0,0 +1,113 @@
+use skia_safe as skia;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum PathCommand {
+    MoveTo = 0,
+    LineTo = 1,
+    CurveTo = 2,
+    Close = 3,
+}
+
+impl From<f32> for PathCommand {
+    fn from(value: f32) -> Self {
+        match value as u8 {
+            1 => Self::LineTo,
+            2 => Self::CurveTo,
+            3 => Self::Close,
+            _ => Self::MoveTo,
+        }
+    }
+}
+
+/// A single raw path command as it arrives from the WASM boundary:
+/// the command tag followed by up to three (x, y) pairs.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RawPathData {
+    pub command: f32,
+    pub params: [f32; 6],
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Path {
+    skia_path: skia::Path,
+}
+
+impl Path {
+    pub fn new(skia_path: skia::Path) -> Self {
+        Self { skia_path }
+    }
+
+    pub fn to_skia_path(&self) -> skia::Path {
+        self.skia_path.clone()
+    }
+
+    /// Renders this path as an SVG `d` attribute value.
+    pub fn to_svg_path_data(&self) -> String {
+        use skia::path::Verb;
+        use std::fmt::Write as _;
+
+        let mut d = String::new();
+        for (verb, points) in self.skia_path.iter() {
+            match verb {
+                Verb::Move => {
+                    let _ = write!(d, "M{} {} ", points[0].x, points[0].y);
+                }
+                Verb::Line => {
+                    let _ = write!(d, "L{} {} ", points[1].x, points[1].y);
+                }
+                Verb::Quad => {
+                    let _ = write!(
+                        d,
+                        "Q{} {} {} {} ",
+                        points[1].x, points[1].y, points[2].x, points[2].y
+                    );
+                }
+                Verb::Cubic => {
+                    let _ = write!(
+                        d,
+                        "C{} {} {} {} {} {} ",
+                        points[1].x, points[1].y, points[2].x, points[2].y, points[3].x, points[3].y
+                    );
+                }
+                Verb::Close => {
+                    d.push_str("Z ");
+                }
+                _ => {}
+            }
+        }
+
+        d.trim_end().to_string()
+    }
+}
+
+impl TryFrom<Vec<RawPathData>> for Path {
+    type Error = String;
+
+    fn try_from(buffer: Vec<RawPathData>) -> Result<Self, Self::Error> {
+        let mut path = skia::Path::new();
+
+        for raw in buffer.into_iter() {
+            match PathCommand::from(raw.command) {
+                PathCommand::MoveTo => {
+                    path.move_to((raw.params[0], raw.params[1]));
+                }
+                PathCommand::LineTo => {
+                    path.line_to((raw.params[0], raw.params[1]));
+                }
+                PathCommand::CurveTo => {
+                    path.cubic_to(
+                        (raw.params[0], raw.params[1]),
+                        (raw.params[2], raw.params[3]),
+                        (raw.params[4], raw.params[5]),
+                    );
+                }
+                PathCommand::Close => {
+                    path.close();
+                }
+            }
+        }
+
+        Ok(Self::new(path))
+    }
+}
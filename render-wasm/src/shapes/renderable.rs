@@ -0,0 +1,40 @@
+use skia_safe as skia;
+
+use crate::math::Rect;
+use crate::render::Renderable;
+
+use super::{Kind, Shape};
+
+impl Shape {
+    /// The shape's bounding box in local coordinates, before `transform`
+    /// and `rotation` are applied.
+    pub fn bounds(&self) -> Rect {
+        match &self.kind {
+            Kind::Rect(r) | Kind::Circle(r) => *r,
+            Kind::Path(_) | Kind::Bool(_, _) => self.selrect,
+        }
+    }
+}
+
+impl Renderable for Shape {
+    fn render(&self, canvas: &skia::Canvas) {
+        if self.hidden {
+            return;
+        }
+
+        canvas.save();
+
+        for fill in self.fills() {
+            let mut paint = fill.to_paint(&self.bounds());
+            paint.set_alpha_f(paint.alpha_f() * self.opacity);
+            canvas.draw_rect(self.bounds().to_skia_rect(), &paint);
+        }
+
+        for stroke in self.strokes() {
+            let paint = stroke.to_paint(&self.bounds());
+            canvas.draw_rect(self.bounds().to_skia_rect(), &paint);
+        }
+
+        canvas.restore();
+    }
+}
@@ -0,0 +1,206 @@
+use skia_safe as skia;
+
+use super::Fill;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Dash {
+    intervals: Vec<f32>,
+    offset: f32,
+}
+
+impl Dash {
+    /// Builds a dash pattern from raw on/off lengths. Odd-length patterns
+    /// are padded by repeating themselves so the on/off phase still
+    /// alternates correctly; an all-zero pattern collapses to no dash
+    /// (i.e. a solid stroke). A pattern containing a negative length is
+    /// invalid rather than silently corrected — dropping or clamping it
+    /// would shift the on/off phase of every interval after it — so it is
+    /// rejected outright and also falls back to a solid stroke.
+    pub fn new(mut intervals: Vec<f32>, offset: f32) -> Self {
+        if intervals.iter().any(|v| *v < 0.) {
+            intervals.clear();
+        }
+
+        if intervals.len() % 2 != 0 {
+            let padded = intervals.clone();
+            intervals.extend(padded);
+        }
+
+        if intervals.iter().all(|v| *v == 0.) {
+            intervals.clear();
+        }
+
+        Self { intervals, offset }
+    }
+
+    pub fn is_solid(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn to_path_effect(&self) -> Option<skia::PathEffect> {
+        if self.is_solid() {
+            return None;
+        }
+        skia::PathEffect::dash(&self.intervals, self.offset)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineCap {
+    Butt,
+    Round,
+    Square,
+}
+
+impl Default for LineCap {
+    fn default() -> Self {
+        Self::Butt
+    }
+}
+
+impl From<u8> for LineCap {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Round,
+            2 => Self::Square,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl LineCap {
+    fn to_skia_cap(self) -> skia::paint::Cap {
+        match self {
+            Self::Butt => skia::paint::Cap::Butt,
+            Self::Round => skia::paint::Cap::Round,
+            Self::Square => skia::paint::Cap::Square,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineJoin {
+    Miter,
+    Round,
+    Bevel,
+}
+
+impl Default for LineJoin {
+    fn default() -> Self {
+        Self::Miter
+    }
+}
+
+impl From<u8> for LineJoin {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Round,
+            2 => Self::Bevel,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl LineJoin {
+    fn to_skia_join(self) -> skia::paint::Join {
+        match self {
+            Self::Miter => skia::paint::Join::Miter,
+            Self::Round => skia::paint::Join::Round,
+            Self::Bevel => skia::paint::Join::Bevel,
+        }
+    }
+}
+
+/// Skia's default miter limit, used when a stroke doesn't set its own.
+const DEFAULT_MITER_LIMIT: f32 = 4.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stroke {
+    pub(crate) fill: Fill,
+    width: f32,
+    dash: Dash,
+    cap: LineCap,
+    join: LineJoin,
+    miter_limit: f32,
+}
+
+impl Stroke {
+    pub fn new(fill: Fill, width: f32) -> Self {
+        Self {
+            fill,
+            width,
+            dash: Dash::default(),
+            cap: LineCap::default(),
+            join: LineJoin::default(),
+            miter_limit: DEFAULT_MITER_LIMIT,
+        }
+    }
+
+    pub fn set_dash(&mut self, intervals: Vec<f32>, offset: f32) {
+        self.dash = Dash::new(intervals, offset);
+    }
+
+    pub fn set_cap(&mut self, cap: LineCap) {
+        self.cap = cap;
+    }
+
+    pub fn set_join(&mut self, join: LineJoin) {
+        self.join = join;
+    }
+
+    pub fn set_miter_limit(&mut self, miter_limit: f32) {
+        self.miter_limit = miter_limit;
+    }
+
+    pub fn to_paint(&self, bounds: &crate::math::Rect) -> skia::Paint {
+        let mut paint = self.fill.to_paint(bounds);
+        paint.set_style(skia::paint::Style::Stroke);
+        paint.set_stroke_width(self.width);
+        paint.set_stroke_cap(self.cap.to_skia_cap());
+        paint.set_stroke_join(self.join.to_skia_join());
+        paint.set_stroke_miter(self.miter_limit);
+        if let Some(path_effect) = self.dash.to_path_effect() {
+            paint.set_path_effect(path_effect);
+        }
+        paint
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_zero_pattern_is_solid() {
+        let dash = Dash::new(vec![0., 0.], 0.);
+        assert!(dash.is_solid());
+    }
+
+    #[test]
+    fn odd_length_pattern_is_padded_to_even() {
+        let dash = Dash::new(vec![4.], 0.);
+        assert_eq!(dash.intervals, vec![4., 4.]);
+    }
+
+    #[test]
+    fn negative_lengths_reject_the_whole_pattern() {
+        let dash = Dash::new(vec![4., -1., 2., 2.], 0.);
+        assert!(dash.is_solid());
+    }
+
+    #[test]
+    fn line_cap_decodes_from_u8_with_butt_default() {
+        assert_eq!(LineCap::from(0), LineCap::Butt);
+        assert_eq!(LineCap::from(1), LineCap::Round);
+        assert_eq!(LineCap::from(2), LineCap::Square);
+        assert_eq!(LineCap::from(42), LineCap::Butt);
+    }
+
+    #[test]
+    fn line_join_decodes_from_u8_with_miter_default() {
+        assert_eq!(LineJoin::from(0), LineJoin::Miter);
+        assert_eq!(LineJoin::from(1), LineJoin::Round);
+        assert_eq!(LineJoin::from(2), LineJoin::Bevel);
+        assert_eq!(LineJoin::from(42), LineJoin::Miter);
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use uuid::Uuid;
+
+use super::{Kind, Shape};
+
+/// Serializes the shape tree rooted at `id` as a standalone SVG document.
+/// This is a pixel-independent export path: it walks `Kind`/`Fill`/`Stroke`
+/// directly instead of going through the Skia GPU renderer, which also
+/// makes it useful for headless geometry snapshot tests.
+pub fn to_svg_document(id: Uuid, shapes: &HashMap<Uuid, Shape>) -> String {
+    let Some(root) = shapes.get(&id) else {
+        return String::new();
+    };
+
+    let bounds = root.selrect;
+    let mut body = String::new();
+    write_shape(id, shapes, &mut body);
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">{}</svg>",
+        bounds.left,
+        bounds.top,
+        bounds.width(),
+        bounds.height(),
+        body
+    )
+}
+
+fn write_shape(id: Uuid, shapes: &HashMap<Uuid, Shape>, out: &mut String) {
+    let Some(shape) = shapes.get(&id) else {
+        return;
+    };
+
+    // `opacity`/`hidden` apply to the whole node — including its children,
+    // the way they would cascade in the real scene graph — so they're
+    // written onto a wrapping `<g>` rather than the shape's own element.
+    let mut group_style = String::new();
+    let _ = write!(group_style, "opacity:{};", shape.opacity);
+    if shape.hidden {
+        group_style.push_str("display:none;");
+    }
+    let _ = write!(out, "<g style=\"{group_style}\">");
+
+    let mut defs = String::new();
+    let mut style = String::new();
+
+    // An SVG shape element only has a single `fill`/`stroke`, but `Shape`
+    // keeps a stack of each with the last one painted on top — so, to
+    // match what actually renders, only the topmost fill/stroke is
+    // exported. Writing every entry into one `style` string would let each
+    // later `fill:`/`stroke:` silently overwrite the previous one anyway,
+    // while still emitting unused `<defs>` for the ones that lose.
+    match shape.fills.last() {
+        Some(fill) => {
+            let gradient_id = format!("fill-{id}-0");
+            let (value, def) = fill.to_svg_attr(&gradient_id);
+            let _ = write!(style, "fill:{value};");
+            if let Some(def) = def {
+                defs.push_str(&def);
+            }
+        }
+        None => style.push_str("fill:none;"),
+    }
+
+    if let Some(stroke) = shape.strokes.last() {
+        let gradient_id = format!("stroke-{id}-0");
+        let (value, def) = stroke.fill.to_svg_attr(&gradient_id);
+        let _ = write!(style, "stroke:{value};");
+        if let Some(def) = def {
+            defs.push_str(&def);
+        }
+    }
+
+    let transform = shape.transform.to_svg_matrix();
+
+    match &shape.kind {
+        Kind::Rect(rect) => {
+            let _ = write!(
+                out,
+                "{defs}<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" transform=\"{transform}\" style=\"{style}\"/>",
+                rect.left, rect.top, rect.width(), rect.height()
+            );
+        }
+        Kind::Circle(rect) => {
+            let center = rect.center();
+            let _ = write!(
+                out,
+                "{defs}<ellipse cx=\"{}\" cy=\"{}\" rx=\"{}\" ry=\"{}\" transform=\"{transform}\" style=\"{style}\"/>",
+                center.x, center.y, rect.width() / 2., rect.height() / 2.
+            );
+        }
+        Kind::Path(path) | Kind::Bool(_, path) => {
+            let _ = write!(
+                out,
+                "{defs}<path d=\"{}\" transform=\"{transform}\" style=\"{style}\"/>",
+                path.to_svg_path_data()
+            );
+        }
+    }
+
+    // A `Bool` shape's children are the operands already folded into its
+    // own cached path above, not independent drawables — emitting them too
+    // would draw every operand on top of the combined result.
+    if !matches!(shape.kind, Kind::Bool(..)) {
+        for child_id in &shape.children {
+            write_shape(*child_id, shapes, out);
+        }
+    }
+
+    out.push_str("</g>");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shapes::{BoolType, Color, Fill};
+
+    #[test]
+    fn renders_a_solid_rect_with_its_geometry_and_fill() {
+        let mut shapes = HashMap::new();
+        let id = Uuid::new_v4();
+
+        let mut shape = Shape::new(id);
+        shape.set_selrect(0., 0., 10., 20.);
+        shape.add_fill(Fill::Solid(Color::from_argb(255, 255, 0, 0)));
+        shapes.insert(id, shape);
+
+        let svg = to_svg_document(id, &shapes);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("<rect"));
+        assert!(svg.contains("width=\"10\""));
+        assert!(svg.contains("height=\"20\""));
+        assert!(svg.contains("fill:#ff0000ff"));
+    }
+
+    #[test]
+    fn only_the_topmost_fill_is_exported() {
+        let mut shapes = HashMap::new();
+        let id = Uuid::new_v4();
+
+        let mut shape = Shape::new(id);
+        shape.set_selrect(0., 0., 10., 10.);
+        shape.add_fill(Fill::Solid(Color::from_argb(255, 255, 0, 0)));
+        shape.add_fill(Fill::Solid(Color::from_argb(255, 0, 255, 0)));
+        shapes.insert(id, shape);
+
+        let svg = to_svg_document(id, &shapes);
+
+        assert_eq!(svg.matches("fill:").count(), 1);
+        assert!(svg.contains("fill:#00ff00ff"));
+    }
+
+    #[test]
+    fn bool_operand_children_are_not_exported_as_their_own_elements() {
+        let mut shapes = HashMap::new();
+
+        let mut child = Shape::new(Uuid::new_v4());
+        child.set_selrect(0., 0., 10., 10.);
+        let child_id = child.id;
+        shapes.insert(child_id, child);
+
+        let mut root = Shape::new(Uuid::new_v4());
+        root.set_selrect(0., 0., 10., 10.);
+        root.set_bool_type(BoolType::Union);
+        root.add_child(child_id);
+        let root_id = root.id;
+        shapes.insert(root_id, root);
+
+        let svg = to_svg_document(root_id, &shapes);
+
+        assert_eq!(svg.matches("<path").count(), 1);
+        assert_eq!(svg.matches("<rect").count(), 0);
+    }
+
+    #[test]
+    fn group_opacity_and_hidden_cascade_to_children() {
+        let mut shapes = HashMap::new();
+
+        let mut child = Shape::new(Uuid::new_v4());
+        child.set_selrect(0., 0., 10., 10.);
+        let child_id = child.id;
+        shapes.insert(child_id, child);
+
+        let mut parent = Shape::new(Uuid::new_v4());
+        parent.set_selrect(0., 0., 10., 10.);
+        parent.set_opacity(0.5);
+        parent.set_hidden(true);
+        parent.add_child(child_id);
+        let parent_id = parent.id;
+        shapes.insert(parent_id, parent);
+
+        let svg = to_svg_document(parent_id, &shapes);
+
+        let group_open = svg.find("<g").unwrap();
+        let group_close = svg[group_open..].find('>').unwrap() + group_open;
+        let group_tag = &svg[group_open..=group_close];
+
+        assert!(group_tag.contains("opacity:0.5"));
+        assert!(group_tag.contains("display:none"));
+        // The child's own <rect> element must come after the parent `<g>`,
+        // i.e. nested inside it rather than as a flat sibling.
+        assert!(svg[group_close..].contains("<rect"));
+    }
+}